@@ -63,19 +63,31 @@ fn try_transform() -> Result<StreamOption<Vec<u8>>, Box<dyn Error>> {
         value: "".to_string(),
     };
 
-    for i in 0..input.number {
-        let idx = i as usize;
-        if idx < topics.len() {
+    if input.number > 0 && !topics.is_empty() {
+        let parsed_abi = ethabi::Contract::load(input.abi.as_slice())?;
+        let event = find_event_by_signature(&parsed_abi, topics[0])?;
+        result.index_topic_0 = topics[0].to_string();
+
+        // topics[1..] line up positionally with the event's indexed inputs, in ABI order.
+        let indexed_inputs: Vec<&ethabi::EventParam> =
+            event.inputs.iter().filter(|input| input.indexed).collect();
+
+        for i in 1..input.number {
+            let idx = i as usize;
+            if idx >= topics.len() {
+                break;
+            }
+            let decoded = match indexed_inputs.get(idx - 1) {
+                Some(param) => decode_indexed_topic(topics[idx], &param.kind)?,
+                None => topics[idx].to_string(),
+            };
             match i {
-                0 => result.index_topic_0 = decode_topic(topics[idx], &input.abi)?,
-                1 => result.index_topic_1 = decode_topic(topics[idx], &input.abi)?,
-                2 => result.index_topic_2 = decode_topic(topics[idx], &input.abi)?,
-                3 => result.index_topic_3 = decode_topic(topics[idx], &input.abi)?,
-                4 => result.index_topic_4 = decode_topic(topics[idx], &input.abi)?,
+                1 => result.index_topic_1 = decoded,
+                2 => result.index_topic_2 = decoded,
+                3 => result.index_topic_3 = decoded,
+                4 => result.index_topic_4 = decoded,
                 _ => break,
             }
-        } else {
-            break;
         }
     }
 
@@ -84,29 +96,26 @@ fn try_transform() -> Result<StreamOption<Vec<u8>>, Box<dyn Error>> {
     Ok(Some(result_json))
 }
 
-fn decode_topic(topic: &str, abi: &[u8]) -> Result<String, Box<dyn Error>> {
-    // Parse the ABI
-    let parsed_abi = ethabi::Contract::load(abi)?;
-    
-    // Convert hex topic to H256
+fn find_event_by_signature<'a>(
+    abi: &'a ethabi::Contract,
+    topic0: &str,
+) -> Result<&'a ethabi::Event, Box<dyn Error>> {
+    let topic0_hash = parse_topic_hash(topic0)?;
+    abi.events()
+        .find(|event| event.signature() == topic0_hash)
+        .ok_or_else(|| format!("no event in ABI matches topic0 signature {}", topic0).into())
+}
+
+fn decode_indexed_topic(topic: &str, kind: &ethabi::ParamType) -> Result<String, Box<dyn Error>> {
+    let topic_bytes = hex::decode(topic.trim_start_matches("0x"))?;
+    let tokens = ethabi_decode(&[kind.clone()], &topic_bytes)?;
+    Ok(tokens.iter().map(|token| token.to_string()).collect::<Vec<_>>().join(", "))
+}
+
+fn parse_topic_hash(topic: &str) -> Result<H256, Box<dyn Error>> {
     let topic_bytes = hex::decode(topic.trim_start_matches("0x"))?;
     let mut bytes = [0u8; 32];
     bytes.copy_from_slice(&topic_bytes);
-    let topic_hash = H256::from(bytes);
-    
-    // Find the event in the ABI and decode
-    for event in parsed_abi.events() {
-        if let Ok(decoded) = event.parse_log(ethabi::RawLog {
-            topics: vec![topic_hash],
-            data: vec![],
-        }) {
-            return Ok(decoded.params.iter()
-                .map(|param| param.value.to_string())
-                .collect::<Vec<_>>().join(", "));
-        }
-    }
-    
-    // If we couldn't decode it, return the original topic
-    Ok(topic.to_string())
+    Ok(H256::from(bytes))
 }
 